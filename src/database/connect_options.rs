@@ -0,0 +1,179 @@
+use std::{fmt, future::Future, pin::Pin, sync::Arc, time::Duration};
+
+use crate::{DatabaseConnection, DbErr};
+
+/// A one-shot hook invoked with the freshly established [DatabaseConnection]
+/// right after the pool has been opened. See
+/// [ConnectOptions::set_after_pool_connect].
+pub type AfterPoolConnect = Arc<
+    dyn Fn(&DatabaseConnection) -> Pin<Box<dyn Future<Output = Result<(), DbErr>> + Send>>
+        + Send
+        + Sync,
+>;
+
+/// Defines the configuration for a database connection
+#[derive(Clone)]
+pub struct ConnectOptions {
+    /// The URI of the database
+    pub(crate) url: String,
+    /// Maximum number of connections for the pool
+    pub(crate) max_connections: Option<u32>,
+    /// Minimum number of connections for the pool
+    pub(crate) min_connections: Option<u32>,
+    /// The connection timeout for a packet connection
+    pub(crate) connect_timeout: Option<Duration>,
+    /// Maximum idle time for a particular connection to prevent
+    /// network resource exhaustion
+    pub(crate) idle_timeout: Option<Duration>,
+    /// Enable `sqlx` statement logging
+    pub(crate) sqlx_logging: bool,
+    /// SQL run once on every physical connection as it is opened, including
+    /// reconnects
+    pub(crate) connect_statements: Vec<String>,
+    /// A user-supplied hook run once against the pool after it is established
+    pub(crate) after_pool_connect: Option<AfterPoolConnect>,
+}
+
+impl fmt::Debug for ConnectOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectOptions")
+            .field("url", &self.url)
+            .field("max_connections", &self.max_connections)
+            .field("min_connections", &self.min_connections)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("idle_timeout", &self.idle_timeout)
+            .field("sqlx_logging", &self.sqlx_logging)
+            .field("connect_statements", &self.connect_statements)
+            .field("after_pool_connect", &self.after_pool_connect.is_some())
+            .finish()
+    }
+}
+
+impl ConnectOptions {
+    /// Create new [ConnectOptions] for a [DatabaseConnection] to a database
+    pub fn new(url: String) -> Self {
+        ConnectOptions {
+            url,
+            max_connections: None,
+            min_connections: None,
+            connect_timeout: None,
+            idle_timeout: None,
+            sqlx_logging: true,
+            connect_statements: Vec::new(),
+            after_pool_connect: None,
+        }
+    }
+
+    /// Set the maximum number of connections of the pool
+    pub fn max_connections(&mut self, value: u32) -> &mut Self {
+        self.max_connections = Some(value);
+        self
+    }
+
+    /// Set the minimum number of connections of the pool
+    pub fn min_connections(&mut self, value: u32) -> &mut Self {
+        self.min_connections = Some(value);
+        self
+    }
+
+    /// Set the timeout duration when acquiring a connection
+    pub fn connect_timeout(&mut self, value: Duration) -> &mut Self {
+        self.connect_timeout = Some(value);
+        self
+    }
+
+    /// Set the idle duration before closing a connection
+    pub fn idle_timeout(&mut self, value: Duration) -> &mut Self {
+        self.idle_timeout = Some(value);
+        self
+    }
+
+    /// Enable or disable `sqlx` statement logging
+    pub fn sqlx_logging(&mut self, value: bool) -> &mut Self {
+        self.sqlx_logging = value;
+        self
+    }
+
+    /// Register a **one-shot** hook that runs against the [DatabaseConnection]
+    /// exactly once, immediately after the pool is established.
+    ///
+    /// This is deliberately *not* called `set_after_connect`: unlike sqlx's
+    /// per-connection `after_connect`, the hook here runs a single time, is
+    /// handed the pool-backed [DatabaseConnection], and is **not** replayed on
+    /// reconnects or on the connections the pool opens later. For session state that must hold on every physical
+    /// connection — `search_path`, timezone, `statement_timeout`,
+    /// `application_name` — use [ConnectOptions::set_schema_search_path],
+    /// [ConnectOptions::set_time_zone], or [ConnectOptions::set_connect_statement]
+    /// instead, which are replayed on every connection the pool opens.
+    ///
+    /// # Divergence from a per-connection hook
+    ///
+    /// This intentionally does **not** provide the "runs once per physical
+    /// connection (including reconnects)" guarantee a closure-style hook would
+    /// ideally give. sqlx's `pool_options().after_connect(...)` hands the
+    /// callback a raw `&mut PgConnection`, not a [DatabaseConnection], so a
+    /// `Fn(&DatabaseConnection)` hook cannot be driven from it. Rather than leak
+    /// the backend-specific connection type through this backend-agnostic API,
+    /// the per-connection path is expressed as SQL via the shortcuts above, and
+    /// this closure hook is scoped to one-shot pool setup.
+    pub fn set_after_pool_connect<F>(&mut self, f: F) -> &mut Self
+    where
+        F: Fn(&DatabaseConnection) -> Pin<Box<dyn Future<Output = Result<(), DbErr>> + Send>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.after_pool_connect = Some(Arc::new(f));
+        self
+    }
+
+    /// Run an arbitrary SQL statement on every physical connection as it is
+    /// opened, including reconnects. This is the general-purpose escape hatch
+    /// behind [ConnectOptions::set_schema_search_path] and
+    /// [ConnectOptions::set_time_zone].
+    pub fn set_connect_statement(&mut self, statement: String) -> &mut Self {
+        self.connect_statements.push(statement);
+        self
+    }
+
+    /// Run `SET search_path TO ...` on every physical connection as it is
+    /// opened, giving the pool a reliable schema search path.
+    pub fn set_schema_search_path(&mut self, schema_search_path: String) -> &mut Self {
+        self.connect_statements
+            .push(format!("SET search_path TO {}", schema_search_path));
+        self
+    }
+
+    /// Run `SET TIME ZONE ...` on every physical connection as it is opened.
+    pub fn set_time_zone(&mut self, time_zone: String) -> &mut Self {
+        self.connect_statements
+            .push(format!("SET TIME ZONE '{}'", time_zone));
+        self
+    }
+
+    /// The SQL statements to run on every physical connection as it is opened.
+    pub(crate) fn connect_statements(&self) -> Vec<String> {
+        self.connect_statements.clone()
+    }
+
+    /// Build an `sqlx` pool configuration from these options.
+    pub(crate) fn pool_options<DB>(self) -> sqlx::pool::PoolOptions<DB>
+    where
+        DB: sqlx::Database,
+    {
+        let mut opt = sqlx::pool::PoolOptions::new();
+        if let Some(max_connections) = self.max_connections {
+            opt = opt.max_connections(max_connections);
+        }
+        if let Some(min_connections) = self.min_connections {
+            opt = opt.min_connections(min_connections);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            opt = opt.connect_timeout(connect_timeout);
+        }
+        if let Some(idle_timeout) = self.idle_timeout {
+            opt = opt.idle_timeout(Some(idle_timeout));
+        }
+        opt
+    }
+}
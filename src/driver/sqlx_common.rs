@@ -0,0 +1,47 @@
+use crate::{DbErr, RuntimeErr};
+
+/// Converts an [sqlx::Error] from connecting into a [DbErr::Conn].
+pub(crate) fn sqlx_error_to_conn_err(err: sqlx::Error) -> DbErr {
+    DbErr::Conn(err.to_string())
+}
+
+/// Converts an [sqlx::Error] from an execution into a [DbErr::Exec],
+/// preserving the database's structured diagnostics where available.
+pub(crate) fn sqlx_error_to_exec_err(err: sqlx::Error) -> DbErr {
+    DbErr::Exec(sqlx_error_to_runtime_err(err))
+}
+
+/// Converts an [sqlx::Error] from a query into a [DbErr::Query],
+/// preserving the database's structured diagnostics where available.
+pub(crate) fn sqlx_error_to_query_err(err: sqlx::Error) -> DbErr {
+    DbErr::Query(sqlx_error_to_runtime_err(err))
+}
+
+/// Pull the structured fields out of a driver error, falling back to the flat
+/// message for every error that does not originate from the database server.
+fn sqlx_error_to_runtime_err(err: sqlx::Error) -> RuntimeErr {
+    match &err {
+        sqlx::Error::Database(e) => {
+            // Keep `to_string()` as the message for every backend, so the
+            // `Display` impl stays byte-for-byte backward compatible. Every
+            // driver reports an error code: Postgres and MySQL give the
+            // SQLSTATE, SQLite its own numeric result code. Only Postgres
+            // exposes the richer constraint/table/column/detail/hint fields, so
+            // those stay unset for MySQL and SQLite.
+            let mut runtime = RuntimeErr::from_message(e.to_string());
+            runtime.code = e.code().map(|c| c.into_owned());
+
+            #[cfg(feature = "sqlx-postgres")]
+            if let Some(pg) = e.try_downcast_ref::<sqlx::postgres::PgDatabaseError>() {
+                runtime.constraint = pg.constraint().map(ToOwned::to_owned);
+                runtime.table = pg.table().map(ToOwned::to_owned);
+                runtime.column = pg.column().map(ToOwned::to_owned);
+                runtime.detail = pg.detail().map(ToOwned::to_owned);
+                runtime.hint = pg.hint().map(ToOwned::to_owned);
+            }
+
+            runtime
+        }
+        _ => RuntimeErr::from_message(err.to_string()),
+    }
+}
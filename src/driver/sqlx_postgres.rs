@@ -1,7 +1,12 @@
-use std::{future::Future, pin::Pin};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
 
+use futures::{stream::BoxStream, Stream, StreamExt};
 use sqlx::{
-    postgres::{PgArguments, PgConnectOptions, PgQueryResult, PgRow},
+    postgres::{PgArguments, PgConnectOptions, PgListener, PgQueryResult, PgRow},
     PgPool, Postgres,
 };
 
@@ -41,10 +46,30 @@ impl SqlxPostgresConnector {
             use sqlx::ConnectOptions;
             opt.disable_statement_logging();
         }
-        match options.pool_options().connect_with(opt).await {
-            Ok(pool) => Ok(DatabaseConnection::SqlxPostgresPoolConnection(
-                SqlxPostgresPoolConnection { pool },
-            )),
+        let set_statements = options.connect_statements();
+        let after_pool_connect = options.after_pool_connect.clone();
+        let mut pool_options = options.pool_options();
+        if !set_statements.is_empty() {
+            // Replay the session-setup statements on every physical connection,
+            // so they survive reconnects rather than only the first `acquire`.
+            pool_options = pool_options.after_connect(move |conn| {
+                let set_statements = set_statements.clone();
+                Box::pin(async move {
+                    for sql in &set_statements {
+                        sqlx::Executor::execute(&mut *conn, sql.as_str()).await?;
+                    }
+                    Ok(())
+                })
+            });
+        }
+        match pool_options.connect_with(opt).await {
+            Ok(pool) => {
+                let db = SqlxPostgresConnector::from_sqlx_postgres_pool(pool);
+                if let Some(after_pool_connect) = after_pool_connect {
+                    after_pool_connect(&db).await?;
+                }
+                Ok(db)
+            }
             Err(e) => Err(sqlx_error_to_conn_err(e)),
         }
     }
@@ -69,9 +94,53 @@ impl SqlxPostgresPoolConnection {
                 Err(err) => Err(sqlx_error_to_exec_err(err)),
             }
         } else {
-            Err(DbErr::Exec(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(DbErr::Exec(RuntimeErr::from_message(
+                "Failed to acquire connection from pool.",
+            )))
+        }
+    }
+
+    /// Execute an unprepared batch of SQL on a single acquired connection using
+    /// the simple-query protocol. The statements may be semicolon-separated and
+    /// are sent in one round trip; the returned [ExecResult] aggregates them.
+    pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", sql);
+
+        if let Ok(conn) = &mut self.pool.acquire().await {
+            match sqlx::Executor::execute(conn, sql).await {
+                Ok(res) => Ok(res.into()),
+                Err(err) => Err(sqlx_error_to_exec_err(err)),
+            }
+        } else {
+            Err(DbErr::Exec(RuntimeErr::from_message(
+                "Failed to acquire connection from pool.",
+            )))
+        }
+    }
+
+    /// Execute a sequence of [Statement]s on a single acquired connection,
+    /// returning one [ExecResult] per statement. This avoids paying a pool
+    /// `acquire` per statement as [SqlxPostgresPoolConnection::execute] does.
+    ///
+    /// The statements are **not** run in a transaction, so a failure leaves the
+    /// earlier statements applied; wrap the sequence in
+    /// [SqlxPostgresPoolConnection::begin] if you need atomicity.
+    pub async fn execute_many(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        if let Ok(conn) = &mut self.pool.acquire().await {
+            let mut results = Vec::with_capacity(stmts.len());
+            for stmt in stmts {
+                debug_print!("{}", stmt);
+                let query = sqlx_query(&stmt);
+                match query.execute(&mut *conn).await {
+                    Ok(res) => results.push(res.into()),
+                    Err(err) => return Err(sqlx_error_to_exec_err(err)),
+                }
+            }
+            Ok(results)
+        } else {
+            Err(DbErr::Exec(RuntimeErr::from_message(
+                "Failed to acquire connection from pool.",
+            )))
         }
     }
 
@@ -85,13 +154,13 @@ impl SqlxPostgresPoolConnection {
                 Ok(row) => Ok(Some(row.into())),
                 Err(err) => match err {
                     sqlx::Error::RowNotFound => Ok(None),
-                    _ => Err(DbErr::Query(err.to_string())),
+                    _ => Err(sqlx_error_to_query_err(err)),
                 },
             }
         } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(DbErr::Query(RuntimeErr::from_message(
+                "Failed to acquire connection from pool.",
+            )))
         }
     }
 
@@ -106,9 +175,9 @@ impl SqlxPostgresPoolConnection {
                 Err(err) => Err(sqlx_error_to_query_err(err)),
             }
         } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(DbErr::Query(RuntimeErr::from_message(
+                "Failed to acquire connection from pool.",
+            )))
         }
     }
 
@@ -119,20 +188,68 @@ impl SqlxPostgresPoolConnection {
         if let Ok(conn) = self.pool.acquire().await {
             Ok(QueryStream::from((conn, stmt)))
         } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(DbErr::Query(RuntimeErr::from_message(
+                "Failed to acquire connection from pool.",
+            )))
         }
     }
 
+    /// Subscribe to one or more Postgres `LISTEN`/`NOTIFY` channels, returning a
+    /// [NotificationStream] that yields every [Notification] delivered on those
+    /// channels.
+    ///
+    /// The underlying [PgListener] reconnects transparently when the connection
+    /// is lost — this cannot be turned off — so notifications emitted while the
+    /// connection is being re-established are dropped and are not replayed.
+    pub async fn listen(&self, channels: &[&str]) -> Result<NotificationStream, DbErr> {
+        self.listen_with(channels, false).await
+    }
+
+    /// Like [SqlxPostgresPoolConnection::listen], but lets you control what the
+    /// listener does when the backing pool is closed.
+    ///
+    /// When `ignore_pool_close` is `false` (the default used by
+    /// [SqlxPostgresPoolConnection::listen]) the stream ends once the pool shuts
+    /// down; when `true` the listener keeps trying to reconnect even after the
+    /// pool is closed. This flag does **not** disable [PgListener]'s automatic
+    /// reconnect on connection loss — sqlx exposes no such switch — so a
+    /// notification emitted during a reconnect can still be dropped regardless
+    /// of this setting.
+    pub async fn listen_with(
+        &self,
+        channels: &[&str],
+        ignore_pool_close: bool,
+    ) -> Result<NotificationStream, DbErr> {
+        let mut listener = PgListener::connect_with(&self.pool)
+            .await
+            .map_err(sqlx_error_to_query_err)?;
+        listener.ignore_pool_close(ignore_pool_close);
+        listener
+            .listen_all(channels.iter().copied())
+            .await
+            .map_err(sqlx_error_to_query_err)?;
+        let stream = listener
+            .into_stream()
+            .map(|res| match res {
+                Ok(notification) => Ok(Notification {
+                    channel: notification.channel().to_owned(),
+                    payload: notification.payload().to_owned(),
+                    process_id: notification.process_id() as i32,
+                }),
+                Err(err) => Err(sqlx_error_to_query_err(err)),
+            })
+            .boxed();
+        Ok(NotificationStream { stream })
+    }
+
     /// Bundle a set of SQL statements that execute together.
     pub async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
         if let Ok(conn) = self.pool.acquire().await {
             DatabaseTransaction::new_postgres(conn).await
         } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(DbErr::Query(RuntimeErr::from_message(
+                "Failed to acquire connection from pool.",
+            )))
         }
     }
 
@@ -152,13 +269,45 @@ impl SqlxPostgresPoolConnection {
                 .map_err(|e| TransactionError::Connection(e))?;
             transaction.run(callback).await
         } else {
-            Err(TransactionError::Connection(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            )))
+            Err(TransactionError::Connection(DbErr::Query(RuntimeErr::from_message(
+                "Failed to acquire connection from pool.",
+            ))))
         }
     }
 }
 
+/// A single message received from a Postgres `NOTIFY`, delivered over a
+/// [NotificationStream] opened with [SqlxPostgresPoolConnection::listen].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    /// The channel the notification was published on.
+    pub channel: String,
+    /// The payload string attached to the `NOTIFY`, empty if none was given.
+    pub payload: String,
+    /// The process id of the backend connection that issued the `NOTIFY`.
+    pub process_id: i32,
+}
+
+/// A [Stream] of [Notification]s for the channels subscribed via
+/// [SqlxPostgresPoolConnection::listen].
+pub struct NotificationStream {
+    stream: BoxStream<'static, Result<Notification, DbErr>>,
+}
+
+impl std::fmt::Debug for NotificationStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NotificationStream")
+    }
+}
+
+impl Stream for NotificationStream {
+    type Item = Result<Notification, DbErr>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.stream.as_mut().poll_next(cx)
+    }
+}
+
 impl From<PgRow> for QueryResult {
     fn from(row: PgRow) -> QueryResult {
         QueryResult {
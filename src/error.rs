@@ -0,0 +1,142 @@
+use std::fmt;
+
+/// An error from unsuccessful database operations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DbErr {
+    /// There was a problem with the database connection
+    Conn(String),
+    /// An operation did not execute successfully
+    Exec(RuntimeErr),
+    /// An error occurred while performing a query
+    Query(RuntimeErr),
+}
+
+/// The structured detail of a failed execution or query, carrying the rich
+/// diagnostic fields the database returns instead of flattening them into a
+/// single string.
+///
+/// The fields mirror what Postgres reports (and the subset MySQL/SQLite
+/// expose); every field other than `message` is `None` when the driver did not
+/// supply it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuntimeErr {
+    /// The primary human-readable error message
+    pub message: String,
+    /// The error code the driver reports: the SQLSTATE for Postgres and MySQL,
+    /// e.g. `23505` for a unique violation, and the numeric result code for SQLite
+    pub code: Option<String>,
+    /// The name of the constraint that was violated, if any
+    pub constraint: Option<String>,
+    /// The table associated with the error, if any
+    pub table: Option<String>,
+    /// The column associated with the error, if any
+    pub column: Option<String>,
+    /// An optional secondary message carrying more detail
+    pub detail: Option<String>,
+    /// An optional suggestion about how to resolve the problem
+    pub hint: Option<String>,
+}
+
+impl RuntimeErr {
+    /// Build a [RuntimeErr] carrying only a message, with no structured detail.
+    pub(crate) fn from_message<T: Into<String>>(message: T) -> Self {
+        RuntimeErr {
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl fmt::Display for RuntimeErr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Reproduce the plain message for backward compatibility; callers that
+        // want the structured fields read them off the struct directly.
+        write!(f, "{}", self.message)
+    }
+}
+
+impl DbErr {
+    /// Returns `true` if this error is a unique-constraint violation
+    /// (SQLSTATE `23505`).
+    pub fn is_unique_violation(&self) -> bool {
+        self.has_sqlstate("23505")
+    }
+
+    /// Returns `true` if this error is a foreign-key violation
+    /// (SQLSTATE `23503`).
+    pub fn is_foreign_key_violation(&self) -> bool {
+        self.has_sqlstate("23503")
+    }
+
+    fn has_sqlstate(&self, sqlstate: &str) -> bool {
+        matches!(
+            self,
+            DbErr::Exec(RuntimeErr { code: Some(code), .. })
+                | DbErr::Query(RuntimeErr { code: Some(code), .. })
+            if code == sqlstate
+        )
+    }
+}
+
+impl std::error::Error for DbErr {}
+
+impl fmt::Display for DbErr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Conn(s) => write!(f, "Connection Error: {}", s),
+            Self::Exec(e) => write!(f, "Execution Error: {}", e),
+            Self::Query(e) => write!(f, "Query Error: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn runtime_err_with_code(code: &str) -> RuntimeErr {
+        RuntimeErr {
+            code: Some(code.to_owned()),
+            ..RuntimeErr::from_message("boom")
+        }
+    }
+
+    #[test]
+    fn is_unique_violation_matches_23505() {
+        assert!(DbErr::Exec(runtime_err_with_code("23505")).is_unique_violation());
+        assert!(DbErr::Query(runtime_err_with_code("23505")).is_unique_violation());
+        assert!(!DbErr::Query(runtime_err_with_code("23505")).is_foreign_key_violation());
+    }
+
+    #[test]
+    fn is_foreign_key_violation_matches_23503() {
+        assert!(DbErr::Query(runtime_err_with_code("23503")).is_foreign_key_violation());
+        assert!(!DbErr::Query(runtime_err_with_code("23503")).is_unique_violation());
+    }
+
+    #[test]
+    fn predicates_false_without_code_or_for_conn() {
+        // No SQLSTATE code captured.
+        assert!(!DbErr::Query(RuntimeErr::from_message("boom")).is_unique_violation());
+        assert!(!DbErr::Exec(RuntimeErr::from_message("boom")).is_foreign_key_violation());
+        // Connection errors carry no structured code at all.
+        assert!(!DbErr::Conn("down".to_owned()).is_unique_violation());
+        assert!(!DbErr::Conn("down".to_owned()).is_foreign_key_violation());
+    }
+
+    #[test]
+    fn display_reproduces_message_text() {
+        assert_eq!(
+            DbErr::Conn("down".to_owned()).to_string(),
+            "Connection Error: down"
+        );
+        assert_eq!(
+            DbErr::Exec(RuntimeErr::from_message("boom")).to_string(),
+            "Execution Error: boom"
+        );
+        assert_eq!(
+            DbErr::Query(runtime_err_with_code("23505")).to_string(),
+            "Query Error: boom"
+        );
+    }
+}
@@ -1,12 +1,118 @@
+use std::collections::HashSet;
+
 use crate::{
     unpack_table_ref, ColumnTrait, ColumnType, DbBackend, EntityTrait, Identity, Iterable,
     PrimaryKeyToColumn, PrimaryKeyTrait, RelationTrait, Schema,
 };
 use sea_query::{
-    extension::postgres::{Type, TypeCreateStatement},
-    Alias, ColumnDef, ForeignKeyCreateStatement, Iden, Index, TableCreateStatement,
+    extension::postgres::{Type, TypeCreateStatement, TypeDropStatement},
+    Alias, ColumnDef, ForeignKeyCreateStatement, Iden, Index, Table, TableCreateStatement,
+    TableDropStatement,
 };
 
+/// A single schema-mutating statement, so a heterogeneous batch of enum and
+/// table statements can be returned and applied in order. See
+/// [Schema::create_schema_from_entities], [Schema::schema_builder] and
+/// [SchemaBuilder].
+#[derive(Debug, Clone)]
+pub enum SchemaStatement {
+    /// Create a Postgres enum type
+    CreateType(TypeCreateStatement),
+    /// Create a table
+    CreateTable(TableCreateStatement),
+    /// Drop a table
+    DropTable(TableDropStatement),
+    /// Drop a Postgres enum type
+    DropType(TypeDropStatement),
+}
+
+/// Accumulates entities of differing types into a single ordered schema batch.
+///
+/// Each [SchemaBuilder::add_entity] call may take a different entity type, so a
+/// whole schema can be assembled from `[Cake, Fruit, ...]`. Enum types shared by
+/// more than one entity are emitted only once. Build one via
+/// [Schema::schema_builder].
+#[derive(Debug)]
+pub struct SchemaBuilder {
+    backend: DbBackend,
+    seen_types: HashSet<String>,
+    type_names: Vec<String>,
+    create_types: Vec<TypeCreateStatement>,
+    create_tables: Vec<TableCreateStatement>,
+    drop_tables: Vec<TableDropStatement>,
+}
+
+impl SchemaBuilder {
+    fn new(backend: DbBackend) -> Self {
+        SchemaBuilder {
+            backend,
+            seen_types: HashSet::new(),
+            type_names: Vec::new(),
+            create_types: Vec::new(),
+            create_tables: Vec::new(),
+            drop_tables: Vec::new(),
+        }
+    }
+
+    /// Add an entity of any type to the batch. Enum types already contributed by
+    /// an earlier entity are skipped, so a `CREATE TYPE` is emitted at most once
+    /// per type name.
+    pub fn add_entity<E>(&mut self, entity: E) -> &mut Self
+    where
+        E: EntityTrait,
+    {
+        for (name, stmt) in enum_creates_from_entity(entity, self.backend) {
+            if self.seen_types.insert(name.clone()) {
+                self.type_names.push(name);
+                self.create_types.push(stmt);
+            }
+        }
+        self.create_tables
+            .push(create_table_from_entity(entity, self.backend));
+        self.drop_tables
+            .push(drop_table_from_entity(entity, self.backend));
+        self
+    }
+
+    /// The ordered batch that brings the schema into existence: every enum type
+    /// first, then every table, so a table referencing an enum never runs before
+    /// its type exists.
+    pub fn create_statements(&self) -> Vec<SchemaStatement> {
+        let mut stmts = Vec::with_capacity(self.create_types.len() + self.create_tables.len());
+        stmts.extend(
+            self.create_types
+                .iter()
+                .cloned()
+                .map(SchemaStatement::CreateType),
+        );
+        stmts.extend(
+            self.create_tables
+                .iter()
+                .cloned()
+                .map(SchemaStatement::CreateTable),
+        );
+        stmts
+    }
+
+    /// The ordered teardown batch, the reverse of
+    /// [SchemaBuilder::create_statements]: every table is dropped first, then
+    /// every enum type.
+    pub fn drop_statements(&self) -> Vec<SchemaStatement> {
+        let mut stmts = Vec::with_capacity(self.drop_tables.len() + self.type_names.len());
+        stmts.extend(
+            self.drop_tables
+                .iter()
+                .rev()
+                .cloned()
+                .map(SchemaStatement::DropTable),
+        );
+        stmts.extend(self.type_names.iter().rev().map(|name| {
+            SchemaStatement::DropType(Type::drop().name(Alias::new(name.as_str())).to_owned())
+        }));
+        stmts
+    }
+}
+
 impl Schema {
     /// Creates Postgres enums from an Entity. See [TypeCreateStatement] for more details
     pub fn create_enum_from_entity<E>(&self, entity: E) -> Vec<TypeCreateStatement>
@@ -23,9 +129,85 @@ impl Schema {
     {
         create_table_from_entity(entity, self.backend)
     }
+
+    /// Start a [SchemaBuilder] that assembles an ordered create/drop batch from
+    /// entities of differing types, deduplicating shared enum types. This is the
+    /// higher-level entry point for bringing a whole schema up or down:
+    ///
+    /// ```ignore
+    /// let mut builder = schema.schema_builder();
+    /// builder.add_entity(Cake).add_entity(Fruit);
+    /// let up = builder.create_statements();
+    /// let down = builder.drop_statements();
+    /// ```
+    pub fn schema_builder(&self) -> SchemaBuilder {
+        SchemaBuilder::new(self.backend)
+    }
+
+    /// Creates an ordered batch of statements that bring a schema into existence
+    /// from a set of entities: every Postgres enum type first, then every table,
+    /// so a table referencing an enum never runs before its type exists. Enum
+    /// types shared across entities are emitted only once.
+    pub fn create_schema_from_entities<E, I>(&self, entities: I) -> Vec<SchemaStatement>
+    where
+        E: EntityTrait,
+        I: IntoIterator<Item = E>,
+    {
+        let mut builder = self.schema_builder();
+        for entity in entities {
+            builder.add_entity(entity);
+        }
+        builder.create_statements()
+    }
+
+    /// Creates an ordered batch of statements that tear a schema down, the
+    /// reverse of [Schema::create_schema_from_entities]: every table is dropped
+    /// first, then every Postgres enum type.
+    pub fn drop_schema_from_entities<E, I>(&self, entities: I) -> Vec<SchemaStatement>
+    where
+        E: EntityTrait,
+        I: IntoIterator<Item = E>,
+    {
+        let mut builder = self.schema_builder();
+        for entity in entities {
+            builder.add_entity(entity);
+        }
+        builder.drop_statements()
+    }
+
+    /// Drops a table for an Entity. See [TableDropStatement] for more details
+    pub fn drop_table_from_entity<E>(&self, entity: E) -> TableDropStatement
+    where
+        E: EntityTrait,
+    {
+        drop_table_from_entity(entity, self.backend)
+    }
+
+    /// Drops the Postgres enums used by an Entity. See [TypeDropStatement] for more details
+    pub fn drop_enum_from_entity<E>(&self, entity: E) -> Vec<TypeDropStatement>
+    where
+        E: EntityTrait,
+    {
+        drop_enum_from_entity(entity, self.backend)
+    }
+}
+
+pub(crate) fn create_enum_from_entity<E>(entity: E, backend: DbBackend) -> Vec<TypeCreateStatement>
+where
+    E: EntityTrait,
+{
+    enum_creates_from_entity(entity, backend)
+        .into_iter()
+        .map(|(_, stmt)| stmt)
+        .collect()
 }
 
-pub(crate) fn create_enum_from_entity<E>(_: E, backend: DbBackend) -> Vec<TypeCreateStatement>
+/// Like [create_enum_from_entity], but pairs each statement with its type name
+/// so callers can deduplicate enum types shared across entities.
+pub(crate) fn enum_creates_from_entity<E>(
+    _: E,
+    backend: DbBackend,
+) -> Vec<(String, TypeCreateStatement)>
 where
     E: EntityTrait,
 {
@@ -47,11 +229,42 @@ where
             .as_enum(Alias::new(name))
             .values(values.iter().map(|val| Alias::new(val.as_str())))
             .to_owned();
+        vec.push((name.to_owned(), stmt));
+    }
+    vec
+}
+
+pub(crate) fn drop_enum_from_entity<E>(_: E, backend: DbBackend) -> Vec<TypeDropStatement>
+where
+    E: EntityTrait,
+{
+    if matches!(backend, DbBackend::MySql | DbBackend::Sqlite) {
+        return Vec::new();
+    }
+    let mut vec = Vec::new();
+    for col in E::Column::iter() {
+        let col_def = col.def();
+        let col_type = col_def.get_column_type();
+        if !matches!(col_type, ColumnType::Enum(_, _)) {
+            continue;
+        }
+        let name = match col_type {
+            ColumnType::Enum(s, _) => s.as_str(),
+            _ => unreachable!(),
+        };
+        let stmt = Type::drop().name(Alias::new(name)).to_owned();
         vec.push(stmt);
     }
     vec
 }
 
+pub(crate) fn drop_table_from_entity<E>(entity: E, _backend: DbBackend) -> TableDropStatement
+where
+    E: EntityTrait,
+{
+    Table::drop().table(entity.table_ref()).take()
+}
+
 pub(crate) fn create_table_from_entity<E>(entity: E, backend: DbBackend) -> TableCreateStatement
 where
     E: EntityTrait,
@@ -170,9 +383,91 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::{sea_query::*, tests_cfg::*, DbBackend, EntityName, Schema};
+    use super::SchemaStatement;
+    use crate::{sea_query::*, tests_cfg::*, DbBackend, EntityName, EntityTrait, Schema};
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn test_create_schema_from_entities_orders_types_before_tables() {
+        let schema = Schema::new(DbBackend::Postgres);
+        let stmts = schema.create_schema_from_entities([LunchSet]);
+        let last_type = stmts
+            .iter()
+            .rposition(|s| matches!(s, SchemaStatement::CreateType(_)));
+        let first_table = stmts
+            .iter()
+            .position(|s| matches!(s, SchemaStatement::CreateTable(_)));
+        // LunchSet uses an enum, so at least one enum type must be created, and
+        // every enum create must precede every table create.
+        assert!(last_type.is_some());
+        assert!(first_table.is_some());
+        assert!(last_type.unwrap() < first_table.unwrap());
+    }
+
+    #[test]
+    fn test_schema_builder_dedups_shared_enum_types() {
+        let schema = Schema::new(DbBackend::Postgres);
+        let mut builder = schema.schema_builder();
+        builder.add_entity(LunchSet).add_entity(LunchSet);
+        let type_count = builder
+            .create_statements()
+            .iter()
+            .filter(|s| matches!(s, SchemaStatement::CreateType(_)))
+            .count();
+        // The same entity added twice must not emit its enum type twice.
+        assert_eq!(type_count, schema.create_enum_from_entity(LunchSet).len());
+    }
+
+    #[test]
+    fn test_schema_builder_drop_statements_reverse_create() {
+        let backend = DbBackend::Postgres;
+        let schema = Schema::new(backend);
+        let mut builder = schema.schema_builder();
+        builder.add_entity(Cake).add_entity(Fruit);
+        let drops = builder.drop_statements();
+        // Cake and Fruit carry no enums, so the batch is the two table drops in
+        // reverse insertion order: Fruit before Cake.
+        assert_eq!(drops.len(), 2);
+        match (&drops[0], &drops[1]) {
+            (SchemaStatement::DropTable(first), SchemaStatement::DropTable(second)) => {
+                assert_eq!(
+                    backend.build(first),
+                    backend.build(&Table::drop().table(Fruit.table_ref()).to_owned())
+                );
+                assert_eq!(
+                    backend.build(second),
+                    backend.build(&Table::drop().table(Cake.table_ref()).to_owned())
+                );
+            }
+            _ => panic!("expected two DropTable statements"),
+        }
+    }
+
+    #[test]
+    fn test_drop_table_from_entity() {
+        for builder in [DbBackend::MySql, DbBackend::Postgres, DbBackend::Sqlite] {
+            let schema = Schema::new(builder);
+            assert_eq!(
+                builder.build(&schema.drop_table_from_entity(CakeFillingPrice)),
+                builder.build(&Table::drop().table(CakeFillingPrice.table_ref()).to_owned())
+            );
+        }
+    }
+
+    #[test]
+    fn test_drop_enum_from_entity() {
+        // Enums are a Postgres-only concept; MySQL/SQLite yield nothing.
+        let schema = Schema::new(DbBackend::Postgres);
+        assert_eq!(
+            schema.drop_enum_from_entity(LunchSet).len(),
+            schema.create_enum_from_entity(LunchSet).len()
+        );
+        for builder in [DbBackend::MySql, DbBackend::Sqlite] {
+            let schema = Schema::new(builder);
+            assert!(schema.drop_enum_from_entity(LunchSet).is_empty());
+        }
+    }
+
     #[test]
     fn test_create_table_from_entity_table_ref() {
         for builder in [DbBackend::MySql, DbBackend::Postgres, DbBackend::Sqlite] {